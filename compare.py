@@ -16,37 +16,61 @@ descs = {
     "pseudo": "Pseudo",
     "simulacrum": "Simulacrum",
 
+    "associated_consts": "Associated constants",
+    "associated_type_bounds": "Associated type bounds",
     "associated_types": "Associated types",
     "checkpoint": "Checkpoints",
+    "reset": "Reset",
     "closures": "Closures",
+    "concurrency": "Concurrency",
     "reference_parameters": "Reference parameters",
     "consume_parameters": "Consume parameters",
     "consume_self": "Consume self",
     "doctest": "Doctest",
+    "attributes": "Attributes",
+    "expectation_precedence": "Expectation precedence",
     "external_trait": "External traits",
+    "std_traits": "Std traits",
     "foreign": "Foreign",
+    "foreign_parameters": "Foreign parameters",
+    "generic_associated_types": "Generic associated types",
     "generic_method": "Generic methods",
     "generic_method_with_lifetime": "Generic methods with lifetime parameters",
+    "lifetime_trait": "Traits with lifetime parameters",
+    "lifetime_struct": "Structs with lifetime parameters",
+    "generic_non_static": "Generic methods with non-'static parameters",
     "generic_return": "Generic return",
     "generic_struct": "Generic structs",
+    "generic_trait_multi_impl": "Generic traits with multiple impls",
     "generic_trait": "Generic traits",
+    "const_generics": "Const generics",
     "impl_trait": "Impl Trait",
+    "impl_trait_argument": "Impl Trait arguments",
+    "impl_trait_in_trait": "Impl Trait in traits",
     "inherited_trait": "Inherited traits",
     "match_method": "Match function",
     "mock_struct": "Structs",
     "mock_trait": "Traits",
+    "nested_mocks": "Nested mocks",
     "multi_trait": "Multiple traits",
     "return_call_with_args": "Return call with args",
+    "return_dyn_iterator": "Return boxed iterator",
     "return_reference": "Return reference",
     "return_mutable_reference": "Return mutable reference",
     "return_owned": "Return owned",
+    "return_result": "Return Result",
     "return_parameters": "Return parameters",
+    "return_sequence": "Return sequence",
     "send": "Send",
     "sequence": "Sequence",
     "static_method": "Static methods",
+    "static_isolation": "Static isolation",
+    "constructor": "Constructors",
     "times_range": "Times range",
+    "unsafe_methods": "Unsafe methods",
     "where_clause": "Where clauses",
 
+    "deref": "Deref wrappers",
     "derive": "Derive",
     "fallback": "Fallback",
     "match_combo": "Match combinations",
@@ -54,6 +78,11 @@ descs = {
     "match_operator": "Match operator",
     "match_pattern": "Match pattern",
     "match_range": "Match range",
+    "match_struct_fields": "Match struct fields",
+    "match_relation": "Match relation",
+    "match_float": "Match float",
+    "match_string": "Match string",
+    "match_collection": "Match collection",
     "match_wildcard": "Match wildcard",
     "modules": "Mock modules",
     "return_constant": "Return a constant",
@@ -63,10 +92,13 @@ descs = {
     "times_any": "Times any",
     "times_n": "Times n",
     "times_never": "Times never",
+    "call_history": "Call history",
+    "spy": "Spy",
 
     "many_args": "Maximum arguments",
     "rustc": "Rustc",
     "first_release": "First release",
+    "verification": "Verification",
     "version": "Tested version",
     "link": "Current version",
 }
@@ -143,19 +175,36 @@ results['mock_it']['first_release'] = "Mar-11-2018 -"
 libnames = sorted(results.keys())
 lib_headers = "|_. ".join([descs[l] for l in libnames])
 print "|_. |_.%s|" % lib_headers
-essential_features = ["associated_types", "checkpoint", "closures",
-        "reference_parameters", "consume_parameters", "consume_self", "doctest",
-        "external_trait", "foreign", "generic_method",
-        "generic_method_with_lifetime", "generic_return",
-        "generic_struct", "generic_trait", "inherited_trait", "match_method",
-        "mock_struct", "mock_trait", "multi_trait", "return_call_with_args",
-        "return_reference", "return_mutable_reference", "return_owned",
-        "return_parameters", "send", "sequence", "static_method", "times_range",
-        "where_clause"]
-convenience_features = [ "derive", "fallback", "impl_trait", "match_combo",
+essential_features = ["associated_consts", "associated_type_bounds",
+        "associated_types", "checkpoint", "reset", "expectation_precedence",
+        "verification", "closures",
+        "concurrency", "constructor", "reference_parameters",
+        "consume_parameters",
+        "consume_self", "doctest", "attributes", "external_trait", "std_traits",
+        "foreign",
+        "foreign_parameters", "generic_associated_types", "generic_method",
+        "generic_method_with_lifetime", "generic_non_static", "lifetime_trait",
+        "lifetime_struct",
+        "generic_return",
+        "generic_struct", "generic_trait",
+        "generic_trait_multi_impl", "const_generics", "inherited_trait",
+        "match_method",
+        "mock_struct", "mock_trait", "multi_trait", "nested_mocks",
+        "return_call_with_args",
+        "return_dyn_iterator", "return_reference", "return_mutable_reference",
+        "deref",
+        "return_owned", "return_result", "return_parameters", "return_sequence",
+        "send", "sequence",
+        "static_method",
+        "static_isolation", "times_range", "unsafe_methods", "where_clause"]
+convenience_features = [ "derive", "fallback", "impl_trait",
+        "impl_trait_argument", "impl_trait_in_trait", "match_combo",
         "match_constant", "match_operator", "match_pattern", "match_range",
+        "match_struct_fields", "match_relation", "match_float", "match_string",
+        "match_collection",
         "match_wildcard", "modules", "return_constant", "return_default",
-        "return_panic", "times_once", "times_any", "times_n", "times_never"]
+        "return_panic", "times_once", "times_any", "times_n", "times_never",
+        "call_history", "spy"]
 other_features = [ "many_args", "rustc", "first_release", "version", "link"]
 print "|\\10=. Essential Features|"
 for feature in essential_features:
@@ -0,0 +1,22 @@
+// vim: tw=80
+//! Traits for the `external_trait` test.  They're defined in a separate crate
+//! so that attribute macros like `#[automock]` can't see their definitions.
+
+/// A `Trait` with a default method
+pub trait Defaulted {
+    fn foo(&self, x: i16) -> i32;
+    fn bar(&self) -> i32 {
+        42
+    }
+}
+
+/// A generic `Trait`
+pub trait Generic<T> {
+    fn foo(&self, t: T) -> u32;
+}
+
+/// A `Trait` with an associated type
+pub trait Associated {
+    type Key;
+    fn foo(&self, k: Self::Key) -> bool;
+}
@@ -6,40 +6,73 @@
 #[allow(unused)]
 macro_rules! test {
     ( $self:ident) => {
+        #[test] fn associated_consts() { $self::associated_consts() }
+        #[test] fn associated_type_bounds() {
+            $self::associated_type_bounds()
+        }
         #[test] fn associated_types() { $self::associated_types() }
+        #[test] fn attributes() { $self::attributes() }
+        #[test] fn call_history() { $self::call_history() }
         #[test] fn checkpoint() { $self::checkpoint() }
         #[test] fn closures() { $self::closures() }
+        #[test] fn concurrency() { $self::concurrency() }
+        #[test] fn const_generics() { $self::const_generics() }
+        #[test] fn constructor() { $self::constructor() }
         #[test] fn consume_parameters() { $self::consume_parameters() }
         #[test] fn consume_self() { $self::consume_self() }
+        #[test] fn deref() { $self::deref() }
         #[test] fn derive() { $self::derive() }
+        #[test] fn expectation_precedence() {
+            $self::expectation_precedence()
+        }
         #[test] fn external_trait() { $self::external_trait() }
         #[test] fn fallback() { $self::fallback() }
         #[test] fn foreign() { $self::foreign() }
+        #[test] fn foreign_parameters() { $self::foreign_parameters() }
+        #[test] fn generic_associated_types() {
+            $self::generic_associated_types()
+        }
         #[test] fn generic_method() { $self::generic_method() }
         #[test] fn generic_method_with_lifetime() {
             $self::generic_method_with_lifetime()
         }
+        #[test] fn generic_non_static() { $self::generic_non_static() }
         #[test] fn generic_return() { $self::generic_return() }
         #[test] fn generic_struct() { $self::generic_struct() }
         #[test] fn generic_trait() { $self::generic_trait() }
+        #[test] fn generic_trait_multi_impl() {
+            $self::generic_trait_multi_impl()
+        }
         #[test] fn impl_trait() { $self::impl_trait() }
+        #[test] fn impl_trait_argument() { $self::impl_trait_argument() }
+        #[test] fn impl_trait_in_trait() { $self::impl_trait_in_trait() }
         #[test] fn inherited_trait() { $self::inherited_trait() }
+        #[test] fn lifetime_struct() { $self::lifetime_struct() }
+        #[test] fn lifetime_trait() { $self::lifetime_trait() }
         #[test] fn many_args() { $self::many_args() }
+        #[test] fn match_collection() { $self::match_collection() }
         #[test] fn match_combo() { $self::match_combo() }
         #[test] fn match_constant() { $self::match_constant() }
+        #[test] fn match_float() { $self::match_float() }
         #[test] fn match_method() { $self::match_method() }
         #[test] fn match_operator() { $self::match_operator() }
         #[test] fn match_pattern() { $self::match_pattern() }
         #[test] fn match_range() { $self::match_range() }
+        #[test] fn match_relation() { $self::match_relation() }
+        #[test] fn match_string() { $self::match_string() }
+        #[test] fn match_struct_fields() { $self::match_struct_fields() }
         #[test] fn match_wildcard() { $self::match_wildcard() }
         #[test] fn mock_struct() { $self::mock_struct() }
         #[test] fn mock_trait() { $self::mock_trait() }
         #[test] fn modules() { $self::modules() }
         #[test] fn multi_trait() { $self::multi_trait() }
+        #[test] fn nested_mocks() { $self::nested_mocks() }
         #[test] fn reference_parameters() { $self::reference_parameters() }
+        #[test] fn reset() { $self::reset() }
         #[test] fn return_call_with_args() { $self::return_call_with_args() }
         #[test] fn return_constant() { $self::return_constant() }
         #[test] fn return_default() { $self::return_default() }
+        #[test] fn return_dyn_iterator() { $self::return_dyn_iterator() }
         #[test] fn return_reference() { $self::return_reference() }
         #[test] fn return_mutable_reference() {
             $self::return_mutable_reference()
@@ -49,14 +82,21 @@ macro_rules! test {
             $self::return_panic()
         }
         #[test] fn return_parameters() { $self::return_parameters() }
+        #[test] fn return_result() { $self::return_result() }
+        #[test] fn return_sequence() { $self::return_sequence() }
         #[test] fn send() { $self::send() }
         #[test] fn sequence() { $self::sequence() }
+        #[test] fn spy() { $self::spy() }
+        #[test] fn static_isolation() { $self::static_isolation() }
         #[test] fn static_method() { $self::static_method() }
+        #[test] fn std_traits() { $self::std_traits() }
         #[test] fn times_any() { $self::times_any() }
         #[test] fn times_n() { $self::times_n() }
         #[test] fn times_never() { $self::times_never() }
         #[test] fn times_once() { $self::times_once() }
         #[test] fn times_range() { $self::times_range() }
+        #[test] fn unsafe_methods() { $self::unsafe_methods() }
+        #[test] fn verification() { $self::verification() }
         #[test] fn version() { $self::version() }
         #[test] fn where_clause() { $self::where_clause() }
         #[test] fn link() {
@@ -65,6 +105,37 @@ macro_rules! test {
     }
 }
 
+/// Defines the `attributes` test's trait, whose methods carry attributes,
+/// after any attributes given first, like a library's mock attribute.  Also
+/// defines `check_attributes`, which calls every method on a mock of it.
+#[allow(unused)]
+macro_rules! attributes_trait {
+    ($(#[$attr:meta])* $name:ident) => {
+        /// A trait whose methods have attributes
+        $(#[$attr])*
+        pub trait $name {
+            /// An ordinary, documented method
+            fn foo(&self) -> u32;
+            // any() is always false, even with --all-features
+            #[cfg(any())]
+            fn gated(&self) -> u32;
+            #[deprecated(note = "Use foo instead")]
+            fn old(&self) -> u32;
+            #[must_use]
+            fn checked(&self) -> u32;
+        }
+
+        /// Calls each method of a mock that returns 1, 2, and 3 from them
+        pub fn check_attributes<M: $name>(mock: &M) {
+            assert_eq!(1, mock.foo());
+            #[allow(deprecated)]
+            let old = mock.old();
+            assert_eq!(2, old);
+            assert_eq!(3, mock.checked());
+        }
+    }
+}
+
 mod t_double;
 mod t_galvanic_mock;
 //// Disable mock_derive until it gets fixed for newer nightly toolchains
@@ -82,20 +153,141 @@ mod t_simulacrum;
 #[derive(Debug, Eq, PartialEq)]
 pub struct UniquelyOwned(u32);
 
+/// The kind of a `Request`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Kind {
+    Read(u32),
+    Write(u32)
+}
+
+/// A struct argument whose fields an expectation can match individually
+#[derive(Clone, Debug, PartialEq)]
+pub struct Request {
+    pub id: u32,
+    pub kind: Kind,
+    pub tags: Vec<u32>
+}
+
+impl Request {
+    /// Three requests, matching respectively `id == 1`, a `Write` kind, and a
+    /// 42 tag, and nothing else.
+    pub fn samples() -> [Request; 3] {
+        [Request{id: 1, kind: Kind::Read(0), tags: vec![]},
+         Request{id: 2, kind: Kind::Write(5), tags: vec![]},
+         Request{id: 3, kind: Kind::Read(0), tags: vec![7, 42]}]
+    }
+}
+
+/// A newtype like those that hand out trait objects in smart-pointer APIs.  It
+/// derefs to the mock it wraps, so tests can set expectations through it.
+pub struct Handle<M>(pub M);
+
+impl<M> Handle<M> {
+    /// The wrapped mock, reached through `Deref`, `AsRef` and `Borrow`
+    pub fn views(&self) -> [&M; 3] {
+        [&**self, self.as_ref(), std::borrow::Borrow::borrow(self)]
+    }
+}
+
+impl<M> AsRef<M> for Handle<M> {
+    fn as_ref(&self) -> &M { &self.0 }
+}
+
+impl<M> std::borrow::Borrow<M> for Handle<M> {
+    fn borrow(&self) -> &M { &self.0 }
+}
+
+impl<M> std::ops::Deref for Handle<M> {
+    type Target = M;
+    fn deref(&self) -> &M { &self.0 }
+}
+
+impl<M> std::ops::DerefMut for Handle<M> {
+    fn deref_mut(&mut self) -> &mut M { &mut self.0 }
+}
+
+/// Names the rule that picks between two overlapping expectations.  A general
+/// expectation returns 1 and a specific one returns 2.  The arguments are the
+/// results of a call that matches both, with the general expectation defined
+/// first and with the specific one defined first.
+pub fn precedence(general_first: std::thread::Result<u32>,
+                  specific_first: std::thread::Result<u32>) -> &'static str
+{
+    match (general_first, specific_first) {
+        (Ok(1), Ok(2)) => "FIFO",
+        (Ok(2), Ok(1)) => "LIFO",
+        (Ok(2), Ok(2)) => "specific",
+        (Ok(_), Ok(_)) => "general",
+        _ => "error"
+    }
+}
+
+/// Names what happens when a call matches two expectations of once each, and
+/// the first call already saturated one of them.
+pub fn saturation(first: u32, second: std::thread::Result<u32>)
+    -> &'static str
+{
+    match second {
+        Ok(x) if x == first => "then repeats",
+        Ok(_) => "then next",
+        Err(_) => "then panics"
+    }
+}
+
+/// Runs `f`, which must panic, in a child process, and reports whether the
+/// process aborted.  The child is this same test binary, filtered to the
+/// calling test, which must reach `f` by calling `aborts` again.  `module` is
+/// the caller's `module_path!()`.
+pub fn aborts<F: FnOnce()>(module: &str, test: &str, f: F) -> &'static str {
+    const CHILD: &str = "MOCK_SHOOTOUT_ABORT_PROBE";
+    if std::env::var_os(CHILD).is_some() {
+        f();
+        unreachable!("The probe should've panicked");
+    }
+    let name = format!("{}::{}", module.splitn(2, "::").nth(1).unwrap(), test);
+    let status = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(&[&name[..], "--exact", "--test-threads=1"])
+        .env(CHILD, "1")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .unwrap();
+    // A test that merely fails exits with 101.  An abort kills the process.
+    match status.code() {
+        Some(101) => "no_abort",
+        _ => "aborts"
+    }
+}
+
 pub trait TestSuite {
     const NAME: &'static str;
 
     // Core features.  These are the essential features that cannot be
     // implemented by the user
+    /// A mocked `Trait` can have associated constants
+    fn associated_consts();
+    /// A mocked `Trait` can have associated types with trait bounds
+    fn associated_type_bounds();
     /// A mocked `Trait` can have associated types
     fn associated_types();
     /// A mock can set a barrier.  All expectations defined before the barrier must
     /// be satisified before, and all expectations defined after the barrier
     /// must be satisfied after.
     fn checkpoint();
+    /// A mock's expectations can be cleared, so that the old ones no longer
+    /// apply and new ones can be added.  Reports whether clearing verifies the
+    /// old expectations or discards them.
+    fn reset();
     /// A method with a closure argument can be mocked, and the mock's matcher
     /// and return functions can call the closure.
     fn closures();
+    /// A mock can be shared between threads and called concurrently, and its
+    /// call counts and sequences remain correct.  Reports whether the mock
+    /// must be wrapped in a lock to do so.
+    fn concurrency();
+    /// A `Trait`'s static constructor, returning `Self` or `Result<Self, E>`,
+    /// can be expected to return another configured mock object.
+    fn constructor();
     /// A mock method can take its parameters by reference.
     fn reference_parameters();
     /// A mock method can consume its parameters, passing them by value to an
@@ -103,23 +295,57 @@ pub trait TestSuite {
     fn consume_parameters();
     /// A mock method can consume `self`
     fn consume_self();
-    /// A Mock can be defined for a `Trait` in an external crate;
+    /// A `Trait` with cfg-gated methods, `#[deprecated]` and `#[must_use]`
+    /// methods, and doc comments can be mocked without warnings under
+    /// `#[deny(warnings)]`.
+    fn attributes();
+    /// A Mock can be defined for a `Trait` in an external crate, including
+    /// generic `Trait`s, `Trait`s with associated types, and `Trait`s with
+    /// default methods.
     fn external_trait();
     /// Can mock foreign functions
     fn foreign();
+    /// Can mock foreign functions that take raw pointers, C strings,
+    /// out-parameters, and callbacks, and write through the out-parameters.
+    fn foreign_parameters();
+    /// A `Trait` with a generic associated type, like a lending iterator, can
+    /// be mocked.
+    fn generic_associated_types();
     /// A `Trait` with a method that has generic parameters can be mocked.
     fn generic_method();
     /// A `Trait` with a method that has a generic lifetime parameter can be
     /// mocked.
     fn generic_method_with_lifetime();
+    /// A `Trait` with generic methods whose type parameters aren't `'static`
+    /// can be mocked.  Reports whether that works for a parameter passed a
+    /// type that borrows data, and for a parameter used only behind a
+    /// reference.
+    fn generic_non_static();
+    /// A `Trait` with a lifetime parameter can be mocked, and its mock methods
+    /// can return data borrowed from their arguments.
+    fn lifetime_trait();
+    /// A `struct` with a lifetime parameter can be mocked, and its mock methods
+    /// can return data borrowed from their arguments.
+    fn lifetime_struct();
     /// A `Trait` with a method that has a generic return value can be mocked.
     fn generic_return();
     /// A generic `struct` can be mocked.
     fn generic_struct();
     /// A generic `Trait` can be mocked.
     fn generic_trait();
+    /// A single mock can implement the same generic `Trait` for two different
+    /// type parameters, with independent expectations for each.
+    fn generic_trait_multi_impl();
+    /// A `Trait` with a const generic parameter, and a method with a const
+    /// generic parameter, can be mocked for multiple values of the parameter.
+    fn const_generics();
     /// A mock can be derived for a method that returns "-> impl Trait".
     fn impl_trait();
+    /// A method that takes an "impl Trait" argument can be mocked.
+    fn impl_trait_argument();
+    /// A `Trait` method that returns "-> impl Iterator" can be mocked, and the
+    /// expectation can build the returned iterator.
+    fn impl_trait_in_trait();
     /// An inherited `Trait` can be mocked.
     fn inherited_trait();
     /// A method call can match an argument by an arbitrary method
@@ -130,28 +356,54 @@ pub trait TestSuite {
     fn mock_trait();
     /// A Mock can be defined that implements multiple `Trait`s.
     fn multi_trait();
+    /// A mock method can return a second, already configured mock of a
+    /// different `Trait`, and the expectations of both are verified.  Reports
+    /// whether a sequence can span both mocks.
+    fn nested_mocks();
     /// A mock method can return the result of a function that depends on its
     /// arguments.
     fn return_call_with_args();
+    /// A mock method can return a boxed iterator, built by the expectation.
+    /// Reports whether the iterator may borrow from the mock object, or must
+    /// be `'static`.
+    fn return_dyn_iterator();
     /// A mock method can return a reference with the lifetime of the mock
     /// object.
     fn return_reference();
     /// A mock method can return a mutable reference with the lifetime of the
     /// mock object.
     fn return_mutable_reference();
+    /// A mock can sit behind a newtype that implements `Deref`, `DerefMut`,
+    /// `AsRef` and `Borrow`, with its expectations set through the newtype,
+    /// and a mock method can return a `&dyn Trait` backed by a second mock.
+    /// Reports which of those work.
+    fn deref();
     /// A mock method can return an owned object that is neither `Copy` nor
     /// `Clone`
     fn return_owned();
+    /// A mock method can return a `Result` whose error type isn't `Clone`,
+    /// like `io::Error`.  Reports whether such an expectation can be called
+    /// any number of times.
+    fn return_result();
     /// A mock method can mutate its parameters when supplied by mutable
     /// reference.
     fn return_parameters();
+    /// A mock method can return different values on successive calls.  Reports
+    /// whether that takes a native API, one expectation per call, or a
+    /// stateful closure.
+    fn return_sequence();
     /// A `Send` `Trait` can be mocked
     fn send();
     /// A `Trait` with a static method can be mocked (though the static method
     /// cannot neccessarily be expected)
     fn static_method();
+    /// Concurrent tests can set conflicting expectations on the same static
+    /// method or free function without interfering with each other.
+    fn static_isolation();
     /// An expectation can assert that it's called a variable number of times
     fn times_range();
+    /// A `Trait` with `unsafe` methods, and an `unsafe Trait`, can be mocked
+    fn unsafe_methods();
     /// Structs, traits, and methods with where clauses can be mocked, and the
     /// expectation will satisfy the where clauses.
     fn where_clause();
@@ -173,6 +425,22 @@ pub trait TestSuite {
     fn match_pattern();
     /// A method call can match a range of values
     fn match_range();
+    /// A method call can match a `struct` argument by one of its fields, by a
+    /// nested `enum` variant, or by an element of a `Vec` field.
+    fn match_struct_fields();
+    /// A method call can match a relation between two arguments, like `a < b`.
+    /// Reports how the relation must be expressed.
+    fn match_relation();
+    /// A method call can match a floating-point argument approximately.
+    /// Reports whether that takes a built-in matcher or a closure.
+    fn match_float();
+    /// A method call can match a string argument by prefix, substring, or
+    /// regular expression.  Reports which of those are built in.
+    fn match_string();
+    /// A method call can match a collection argument by an element it
+    /// contains, by its length, or by a condition on each element.  Reports
+    /// which of those are built in.
+    fn match_collection();
     /// A method call can match any argument at all
     fn match_wildcard();
     /// An entire module can be mocked
@@ -191,13 +459,31 @@ pub trait TestSuite {
     fn times_n();
     /// An expectation can assert that it's never called
     fn times_never();
+    /// A mock's calls can be inspected after the fact: how many there were,
+    /// and the arguments of the Nth one.
+    fn call_history();
+    /// A mock can record calls without any expectations, and the test can
+    /// assert afterwards whether it was called with particular arguments.
+    fn spy();
 
     // Quantitative features.  These are measured in degrees, rather than as
     // booleans.  The degree of support is printed to stdout.
     /// How many arguments can be mocked?
     fn many_args();
+    /// When two expectations match the same call, which one wins?  Reports
+    /// whether the first-defined, last-defined, or more specific expectation
+    /// wins, and what happens once the winner has been called as many times
+    /// as it allows.
+    fn expectation_precedence();
+    /// What triggers verification of a mock's expectations: dropping the mock,
+    /// or an explicit call?  For mocks that verify on drop, also reports
+    /// whether one dropped while its thread is already panicking panics again
+    /// and aborts the process.
+    fn verification();
     /// A scenario can expect calls to multiple methods in a specified order
     fn sequence();
+    /// Which traits from the standard library can be mocked?
+    fn std_traits();
     /// Current crate version
     fn version();
 }
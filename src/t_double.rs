@@ -31,12 +31,38 @@ use double::{
     __private_mock_trait_new_impl,
     __private_mock_trait_default_impl
 };
-use crate::TestSuite;
+use crate::{Handle, Kind, Request, TestSuite, precedence};
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    rc::Rc
+};
 
 pub struct MockDouble;
 #[allow(unused_parens)]
 impl TestSuite for MockDouble{
     const NAME: &'static str = "double";
+    fn associated_consts() {
+        pub trait A {
+            const MAX: u32;
+            fn foo(&self, key: i16) -> u32;
+        }
+
+        mock_trait!(
+            MockA,
+            foo(i16) -> u32
+        );
+        impl A for MockA {
+            const MAX: u32 = 42;
+            mock_method!(foo(&self, key: i16) -> u32);
+        }
+
+        let mock = MockA::default();
+        mock.foo.return_value(MockA::MAX);
+        assert_eq!(42, mock.foo(-1));
+    }
+
+    fn associated_type_bounds() { unimplemented!() }
     fn associated_types() { unimplemented!() }
     fn checkpoint() {
         // Double actually supports this feature the same way that Pseudo does,
@@ -69,9 +95,63 @@ impl TestSuite for MockDouble{
         unimplemented!()
     }
 
+    // Like checkpoint, reset_calls prints garbage to stdout
+    fn reset() { unimplemented!() }
+
+    fn expectation_precedence() {
+        // Double has no call limits, so there's nothing to saturate
+        pub trait A {
+            fn foo(&self, x: u32) -> u32;
+        }
+
+        mock_trait!(
+            MockA,
+            foo(u32) -> u32
+        );
+        impl A for MockA {
+            mock_method!(foo(&self, x: u32) -> u32);
+        }
+
+        let mock = MockA::default();
+        mock.foo.return_value(1);
+        mock.foo.return_value_for(5, 2);
+        let general_first = Ok(mock.foo(5));
+        let mock = MockA::default();
+        mock.foo.return_value_for(5, 2);
+        mock.foo.return_value(1);
+        let specific_first = Ok(mock.foo(5));
+        print!("{} ", precedence(general_first, specific_first));
+    }
+
+    fn verification() {
+        // Double has no expectations, so an unused mock drops quietly.  The
+        // test must check the recorded calls itself.
+        pub trait A {
+            fn foo(&self);
+        }
+
+        mock_trait!(
+            MockA,
+            foo() -> ()
+        );
+        impl A for MockA {
+            mock_method!(foo(&self));
+        }
+
+        let mock = MockA::default();
+        mock.foo.return_value(());
+        assert!(!mock.foo.called());
+        drop(mock);
+        print!("explicit ");
+    }
+
     // Double can't handle generic methods
     fn closures() { unimplemented!() }
 
+    // Double's mocks aren't even Send
+    // https://github.com/DonaldWhyte/double/issues/18
+    fn concurrency() { unimplemented!() }
+
     fn reference_parameters() {
         // The mock_trait! macro doesn't provide a way to declare an argument's
         // lifetime
@@ -104,18 +184,105 @@ impl TestSuite for MockDouble{
 
     fn derive() { unimplemented!() }
     fn external_trait() {
-        pub trait A {
-            fn foo(&self, key: i16) -> i32;
-        }
+        // mock_trait! doesn't support generic structs, but the mock can
+        // implement a concrete instantiation of a generic Trait.
+        use external_traits::{Associated, Defaulted, Generic};
 
         mock_trait!(
-            MockA,
+            MockD,
             foo(i16) -> i32
         );
-        impl A for MockA {
-            mock_method!(foo(&self, key: i16) -> i32);
+        impl Defaulted for MockD {
+            mock_method!(foo(&self, x: i16) -> i32);
+        }
+        mock_trait!(
+            MockG,
+            foo(i16) -> u32
+        );
+        impl Generic<i16> for MockG {
+            mock_method!(foo(&self, t: i16) -> u32);
         }
+        mock_trait!(
+            MockA,
+            foo(u16) -> bool
+        );
+        impl Associated for MockA {
+            type Key = u16;
+            mock_method!(foo(&self, k: u16) -> bool);
+        }
+
+        let d = MockD::default();
+        d.foo.return_value(5);
+        assert_eq!(5, d.foo(4));
+        assert!(d.foo.called_with(4));
+        // Default methods needn't be mocked
+        assert_eq!(42, d.bar());
+
+        let g = MockG::default();
+        g.foo.return_value(42);
+        assert_eq!(42, g.foo(-1));
+
+        let a = MockA::default();
+        a.foo.return_value(true);
+        assert!(a.foo(1));
     }
+    fn std_traits() {
+        // mock_method! can't convert io::Result, which isn't Clone, so the
+        // mock methods must be written by hand.  Index can't be mocked,
+        // because it returns a reference.  And Clone can't be mocked, because
+        // MockStd::default would construct a default MockStd to return from
+        // clone, which would construct another, and so on.
+        mock_trait!(
+            MockStd,
+            read() -> Vec<u8>,
+            write(Vec<u8>) -> usize,
+            flush() -> (),
+            next() -> Option<u32>,
+            fmt() -> String
+        );
+        impl Read for MockStd {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let data = self.read.call(());
+                buf[..data.len()].copy_from_slice(&data);
+                Ok(data.len())
+            }
+        }
+        impl Write for MockStd {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                Ok(self.write.call(buf.to_vec()))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(self.flush.call(()))
+            }
+        }
+        impl Iterator for MockStd {
+            type Item = u32;
+            mock_method!(next(&mut self) -> Option<u32>);
+        }
+        impl fmt::Display for MockStd {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.fmt.call(()))
+            }
+        }
+
+        let mut mock = MockStd::default();
+        mock.read.return_value(vec![1, 2, 3]);
+        mock.write.use_closure(Box::new(|buf| buf.len()));
+        mock.next.return_value(Some(42));
+        mock.fmt.return_value(String::from("Hello, World!"));
+
+        let mut buf = [0u8; 8];
+        assert_eq!(3, mock.read(&mut buf).unwrap());
+        assert_eq!([1, 2, 3], buf[..3]);
+        mock.write_all(b"hello").unwrap();
+        mock.flush().unwrap();
+        assert!(mock.write.called_with(b"hello".to_vec()));
+        let taken = Iterator::take(&mut mock, 2).collect::<Vec<_>>();
+        assert_eq!(vec![42, 42], taken);
+        assert_eq!("Hello, World!", mock.to_string());
+        print!("Read Write Iterator Display ");
+    }
+
     fn fallback() { unimplemented!() }
     fn foreign() {
         // Double's documentation describe mocking free functions.  But that's
@@ -125,6 +292,13 @@ impl TestSuite for MockDouble{
         unimplemented!()
     }
 
+    // Double can't mock foreign functions at all
+    fn foreign_parameters() { unimplemented!() }
+
+    // Lending a reference requires storage in the mock object, but
+    // mock_trait! doesn't allow adding fields to the mock struct.
+    fn generic_associated_types() { unimplemented!() }
+
     fn generic_method() {
         // Double's docs describe mocking generic methods by converting the
         // arguments to Strings.  But IMHO, that's too limited, cumbersome, and
@@ -136,6 +310,36 @@ impl TestSuite for MockDouble{
         unimplemented!()
     }
 
+    fn generic_non_static() {
+        // Fails to compile.  Double can't mock generic methods, except by
+        // converting arguments to Strings, because mock_trait! can't name a
+        // method's type parameter.
+        //pub trait A {
+            //fn foo<T: Debug>(&self, t: T);
+            //fn bar<T: Debug>(&self, t: &T);
+        //}
+
+        //mock_trait!(
+            //MockA,
+            //foo(T) -> (),
+            //bar(&T) -> ()
+        //);
+        //impl A for MockA {
+            //mock_method!(foo<T: Debug>(&self, t: T));
+            //mock_method!(bar<T: Debug>(&self, t: &T));
+        //}
+        unimplemented!()
+    }
+
+    fn lifetime_trait() {
+        // mock_trait! can't declare lifetime parameters
+        unimplemented!()
+    }
+    fn lifetime_struct() {
+        // mock_trait! can't declare lifetime parameters
+        unimplemented!()
+    }
+
     fn generic_return() {unimplemented!()}
 
     fn generic_struct() {
@@ -159,9 +363,72 @@ impl TestSuite for MockDouble{
         unimplemented!()
     }
 
+    fn generic_trait_multi_impl() {
+        // mock_method! can't rename a method, so each impl must forward to its
+        // own field by hand.
+        pub trait Handler<E> {
+            fn handle(&self, e: E) -> u32;
+        }
+
+        mock_trait!(
+            MockHandler,
+            handle_u8(u8) -> u32,
+            handle_u16(u16) -> u32
+        );
+        impl Handler<u8> for MockHandler {
+            fn handle(&self, e: u8) -> u32 {
+                self.handle_u8.call(e)
+            }
+        }
+        impl Handler<u16> for MockHandler {
+            fn handle(&self, e: u16) -> u32 {
+                self.handle_u16.call(e)
+            }
+        }
+
+        let mock = MockHandler::default();
+        mock.handle_u8.return_value_for(1, 8);
+        mock.handle_u16.return_value_for(1, 16);
+        assert_eq!(8, Handler::<u8>::handle(&mock, 1));
+        assert_eq!(16, Handler::<u16>::handle(&mock, 1));
+    }
+
+    // Double can't mock generic methods, nor generic traits
+    fn const_generics() { unimplemented!() }
+
     // Can't mock structs at all
     fn impl_trait() {unimplemented!() }
 
+    fn impl_trait_argument() {
+        // mock_method! can't convert the argument, so the mock method must be
+        // written by hand.
+        pub trait A {
+            fn foo(&self, x: impl AsRef<str>) -> usize;
+        }
+
+        mock_trait!(
+            MockA,
+            foo(String) -> usize
+        );
+        impl A for MockA {
+            fn foo(&self, x: impl AsRef<str>) -> usize {
+                self.foo.call(x.as_ref().to_owned())
+            }
+        }
+
+        let mock = MockA::default();
+        mock.foo.use_closure(Box::new(|x| x.len()));
+        assert_eq!(5, mock.foo("Hello"));
+        assert!(mock.foo.called_with(String::from("Hello")));
+    }
+
+    fn impl_trait_in_trait() {
+        // mock_method! can't convert the return value.  A hand-written method
+        // could turn a Vec into an iterator, but then the user's code builds
+        // it, not the expectation.
+        unimplemented!()
+    }
+
     fn inherited_trait() {
         pub trait A {
             fn foo(&self);
@@ -296,6 +563,115 @@ impl TestSuite for MockDouble{
 
     fn match_pattern() { unimplemented!() }
     fn match_range() { unimplemented!() }
+    fn match_struct_fields() {
+        pub trait A {
+            fn foo(&self, req: Request);
+        }
+
+        mock_trait!(
+            MockA,
+            foo(Request) -> ()
+        );
+        impl A for MockA {
+            mock_method!(foo(&self, req: Request));
+        }
+
+        fn has_id_1(r: &Request) -> bool {
+            r.id == 1
+        }
+        fn is_write(r: &Request) -> bool {
+            matches!(r.kind, Kind::Write(_))
+        }
+        fn has_tag_42(r: &Request) -> bool {
+            r.tags.contains(&42)
+        }
+
+        let mock = MockA::default();
+        let [r1, r2, r3] = Request::samples();
+        mock.foo(r1);
+        mock.foo(r2);
+        mock.foo(r3);
+        assert!(mock.foo.called_with_pattern(p!(has_id_1)));
+        assert!(mock.foo.called_with_pattern(p!(is_write)));
+        assert!(mock.foo.called_with_pattern(p!(has_tag_42)));
+    }
+
+    // matcher! applies a separate matcher to each argument, so it can't relate
+    // one argument to another.
+    fn match_relation() { unimplemented!() }
+
+    fn match_float() {
+        pub trait A {
+            fn foo(&self, x: f64);
+        }
+
+        mock_trait!(
+            MockA,
+            foo(f64) -> ()
+        );
+        impl A for MockA {
+            mock_method!(foo(&self, x: f64));
+        }
+
+        let mock = MockA::default();
+        mock.foo(0.1 + 0.2);
+        assert!(mock.foo.called_with_pattern(p!(f64_eq, 0.3)));
+        let mock = MockA::default();
+        mock.foo(0.4);
+        assert!(!mock.foo.called_with_pattern(p!(f64_eq, 0.3)));
+        print!("approx ");
+    }
+
+    fn match_string() {
+        // Double has no regex matcher
+        pub trait A {
+            fn foo(&self, s: String);
+        }
+
+        mock_trait!(
+            MockA,
+            foo(String) -> ()
+        );
+        impl A for MockA {
+            mock_method!(foo(&self, s: String));
+        }
+
+        let mock = MockA::default();
+        mock.foo(String::from("help"));
+        assert!(mock.foo.called_with_pattern(p!(starts_with, "hel")));
+        assert!(!mock.foo.called_with_pattern(p!(has_substr, "orl")));
+        let mock = MockA::default();
+        mock.foo(String::from("world"));
+        assert!(mock.foo.called_with_pattern(p!(has_substr, "orl")));
+        assert!(!mock.foo.called_with_pattern(p!(starts_with, "hel")));
+        print!("prefix contains ");
+    }
+
+    fn match_collection() {
+        // Double has no matcher for each element
+        pub trait A {
+            fn foo(&self, v: Vec<u32>);
+        }
+
+        mock_trait!(
+            MockA,
+            foo(Vec<u32>) -> ()
+        );
+        impl A for MockA {
+            mock_method!(foo(&self, v: Vec<u32>));
+        }
+
+        let mock = MockA::default();
+        mock.foo(vec![0, 42]);
+        assert!(mock.foo.called_with_pattern(p!(contains, 42)));
+        assert!(!mock.foo.called_with_pattern(p!(has_length, 3)));
+        let mock = MockA::default();
+        mock.foo(vec![0, 0, 0]);
+        assert!(mock.foo.called_with_pattern(p!(has_length, 3)));
+        assert!(!mock.foo.called_with_pattern(p!(contains, 42)));
+        print!("contains length ");
+    }
+
     fn match_wildcard() {
         pub trait A {
             fn foo(&self, key: i16);
@@ -333,6 +709,48 @@ impl TestSuite for MockDouble{
     fn mock_struct() { unimplemented!() }
     fn modules() { unimplemented!() }
     fn multi_trait() { unimplemented!() }
+
+    fn nested_mocks() {
+        pub trait Conn {
+            fn query(&self, q: u32) -> u32;
+        }
+        pub trait Factory {
+            fn conn(&self) -> Box<dyn Conn>;
+        }
+
+        mock_trait!(
+            MockConn,
+            query(u32) -> u32
+        );
+        impl Conn for MockConn {
+            mock_method!(query(&self, q: u32) -> u32);
+        }
+        // MockFactory::new takes a default return value that conn() clones,
+        // so every connection it returns is an Rc of the same MockConn.
+        impl Conn for Rc<MockConn> {
+            fn query(&self, q: u32) -> u32 {
+                (**self).query(q)
+            }
+        }
+        mock_trait!(
+            MockFactory,
+            conn() -> Rc<MockConn>
+        );
+        impl Factory for MockFactory {
+            fn conn(&self) -> Box<dyn Conn> {
+                Box::new(self.conn.call(()))
+            }
+        }
+
+        let conn = Rc::new(MockConn::default());
+        conn.query.return_value_for(1, 42);
+        let factory = MockFactory::new(conn.clone());
+        assert_eq!(42, factory.conn().query(1));
+        assert_eq!(1, factory.conn.num_calls());
+        assert!(conn.query.called_with(1));
+        print!("nested ");
+    }
+
     fn return_call_with_args() { 
         pub trait A {
             fn foo(&self, key: i16) -> i16;
@@ -383,6 +801,12 @@ impl TestSuite for MockDouble{
         assert_eq!(0, mock.foo());
     }
 
+    fn return_dyn_iterator() {
+        // Double requires return values to be Clone, and boxed iterators
+        // aren't.  mock_method! can't convert a Vec into one, either.
+        unimplemented!()
+    }
+
     fn return_reference() {
         // mock_method! can't handle "&'a self" parameters
         //struct S();
@@ -402,11 +826,63 @@ impl TestSuite for MockDouble{
 
     fn return_mutable_reference() { unimplemented!() }
 
+    fn deref() {
+        // mock_trait! structs can't hold a second mock to return by reference,
+        // so Double can't return &dyn OtherTrait.
+        pub trait A {
+            fn foo(&self, x: u32) -> u32;
+        }
+
+        mock_trait!(
+            MockA,
+            foo(u32) -> u32
+        );
+        impl A for MockA {
+            mock_method!(foo(&self, x: u32) -> u32);
+        }
+
+        let handle = Handle(MockA::default());
+        handle.foo.return_value_for(1, 42);
+        for m in handle.views().iter() {
+            let a: &dyn A = *m;
+            assert_eq!(42, a.foo(1));
+        }
+        assert!(handle.foo.called_with(1));
+        print!("deref ");
+    }
+
     fn return_owned() { 
         // Double requires that return types be Clone
         unimplemented!()
     }
 
+    fn return_result() {
+        // Double requires return types to be Clone, so the mock must return an
+        // ErrorKind and build the io::Error by hand.
+        pub trait A {
+            fn foo(&self, x: u32) -> io::Result<u32>;
+        }
+
+        mock_trait!(
+            MockA,
+            foo(u32) -> Result<u32, io::ErrorKind>
+        );
+        impl A for MockA {
+            fn foo(&self, x: u32) -> io::Result<u32> {
+                self.foo.call(x).map_err(|k| io::Error::new(k, "Oops"))
+            }
+        }
+
+        let mock = MockA::new(Ok(0));
+        mock.foo.use_closure(Box::new(|x| {
+            if x == 0 { Err(io::ErrorKind::Other) } else { Ok(x) }
+        }));
+        assert!(mock.foo(0).is_err());
+        assert!(mock.foo(0).is_err());
+        assert_eq!(1, mock.foo(1).unwrap());
+        print!("by hand ");
+    }
+
     fn return_panic() {
         unimplemented!()
     }
@@ -417,6 +893,36 @@ impl TestSuite for MockDouble{
         unimplemented!()
     }
 
+    fn return_sequence() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        pub trait A {
+            fn foo(&self) -> Result<u32, String>;
+        }
+
+        mock_trait!(
+            MockA,
+            foo() -> Result<u32, String>
+        );
+        impl A for MockA {
+            mock_method!(foo(&self) -> Result<u32, String>);
+        }
+
+        let mock = MockA::default();
+        let count = AtomicUsize::new(0);
+        mock.foo.use_closure(Box::new(move |_| {
+            match count.fetch_add(1, Ordering::Relaxed) {
+                0 => Ok(1),
+                1 => Ok(2),
+                _ => Err(String::from("Oops"))
+            }
+        }));
+        assert_eq!(Ok(1), mock.foo());
+        assert_eq!(Ok(2), mock.foo());
+        assert!(mock.foo().is_err());
+        print!("closure ");
+    }
+
     // https://github.com/DonaldWhyte/double/issues/18
     fn send() {
         unimplemented!()
@@ -426,6 +932,12 @@ impl TestSuite for MockDouble{
         unimplemented!()
     }
 
+    // Double can't mock static methods at all
+    fn static_isolation() { unimplemented!() }
+
+    // Double can't mock static methods
+    fn constructor() { unimplemented!() }
+
     fn sequence() {
         pub trait A {
             fn foo(&self, x: i32);
@@ -515,6 +1027,48 @@ impl TestSuite for MockDouble{
         assert_eq!(0, mock.foo.num_calls());
     }
 
+    fn call_history() {
+        pub trait A {
+            fn foo(&self, x: i32);
+        }
+
+        mock_trait!(
+            MockA,
+            foo(i32) -> ()
+        );
+        impl A for MockA {
+            mock_method!(foo(&self, x: i32));
+        }
+
+        let mock = MockA::default();
+        mock.foo(1);
+        mock.foo(2);
+        mock.foo(3);
+        assert_eq!(3, mock.foo.num_calls());
+        assert_eq!(2, mock.foo.calls()[1]);
+    }
+
+    fn spy() {
+        pub trait A {
+            fn foo(&self, x: i32);
+        }
+
+        mock_trait!(
+            MockA,
+            foo(i32) -> ()
+        );
+        impl A for MockA {
+            mock_method!(foo(&self, x: i32));
+        }
+
+        let mock = MockA::default();
+        mock.foo(1);
+        mock.foo(2);
+        assert!(mock.foo.called_with(2));
+        assert!(!mock.foo.called_with(3));
+        assert!(mock.foo.has_calls_in_order(vec!(1, 2)));
+    }
+
     fn times_range() { 
         // Double has a different approach to validating the number of
         // calls.  It validates call counts at the end of the test,
@@ -540,6 +1094,71 @@ impl TestSuite for MockDouble{
         assert!(num_calls >= 2 && num_calls < 3);
     }
 
+    fn unsafe_methods() {
+        // mock_method! can't emit unsafe methods, so they must be written by
+        // hand.  But an unsafe Trait's safe methods work as usual.
+        pub trait A {
+            unsafe fn foo(&self, x: i16) -> i32;
+        }
+        pub unsafe trait B {
+            fn foo(&self, x: i16) -> i32;
+        }
+
+        mock_trait!(
+            MockA,
+            foo(i16) -> i32
+        );
+        impl A for MockA {
+            unsafe fn foo(&self, x: i16) -> i32 {
+                self.foo.call(x)
+            }
+        }
+        mock_trait!(
+            MockB,
+            foo(i16) -> i32
+        );
+        unsafe impl B for MockB {
+            mock_method!(foo(&self, x: i16) -> i32);
+        }
+
+        let a = MockA::default();
+        a.foo.return_value(5);
+        assert_eq!(5, unsafe { a.foo(4) });
+        assert!(a.foo.called_with(4));
+
+        let b = MockB::default();
+        b.foo.return_value(5);
+        assert_eq!(5, b.foo(4));
+        assert!(b.foo.called_with(4));
+    }
+
+    fn attributes() {
+        // mock_trait! restates each method, so the user must leave out the
+        // cfg-gated one by hand.
+        #[deny(warnings)]
+        mod m {
+            use double::{mock_method, mock_trait};
+
+            attributes_trait!(A);
+
+            mock_trait!(
+                MockA,
+                foo() -> u32,
+                old() -> u32,
+                checked() -> u32
+            );
+            impl A for MockA {
+                mock_method!(foo(&self) -> u32);
+                mock_method!(old(&self) -> u32);
+                mock_method!(checked(&self) -> u32);
+            }
+        }
+        use m::*;
+
+        let mock = MockA::new(1, 2, 3);
+        check_attributes(&mock);
+    }
+
     fn version() {
         let ver = crate::built_info::DEPENDENCIES.iter()
             .find(|(name, _)| *name == "double")
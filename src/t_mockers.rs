@@ -18,15 +18,34 @@ mod t {
 
 use mockers::*;
 use mockers_derive::*;
-use std::{sync::Arc, cell::RefCell, fmt::Debug};
-use crate::{TestSuite, UniquelyOwned};
-
-pub trait ET {}
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    io::{self, Read, Write},
+    mem,
+    panic,
+    sync::{Arc, Barrier},
+    thread
+};
+use crate::{
+    Handle,
+    Kind,
+    Request,
+    TestSuite,
+    UniquelyOwned,
+    aborts,
+    precedence,
+    saturation
+};
+
+use external_traits::Defaulted;
 
 mock!{
-    ETMock,
-    self,
-    trait ET {}
+    DefaultedMock,
+    external_traits,
+    trait Defaulted {
+        fn foo(&self, x: i16) -> i32;
+    }
 }
 
 pub trait EITA {}
@@ -43,6 +62,19 @@ mock!{
 pub trait EMTA {}
 pub trait EMTB {}
 
+mock!{
+    StdMock,
+    io,
+    trait Read {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+    },
+    io,
+    trait Write {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize>;
+        fn flush(&mut self) -> io::Result<()>;
+    }
+}
+
 mock!{
     EMTBMock,
     self,
@@ -68,6 +100,24 @@ impl TestSuite for Mockers {
         assert_eq!(mock.foo(1), false);
     }
 
+    // Mockers mocks traits as trait objects, and traits with associated
+    // constants aren't object-safe.
+    fn associated_consts() { unimplemented!() }
+
+    fn associated_type_bounds(){
+        #[mocked]
+        pub trait A {
+            type Key: Copy + Debug;
+            type Value: Debug;
+            fn foo(&self, x: Self::Key) -> Self::Value;
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A<Key=u32, Value=bool>>();
+        scenario.expect(mock.foo_call(1).and_return(false));
+        assert_eq!(mock.foo(1), false);
+    }
+
     fn checkpoint(){
         #[mocked]
         pub trait A {
@@ -83,6 +133,98 @@ impl TestSuite for Mockers {
         mock.foo(2);
     }
 
+    fn reset(){
+        // Mockers' only way to clear expectations is checkpoint, which verifies
+        // them first.
+        #[mocked]
+        pub trait A {
+            fn foo(&self, x: i16) -> i32;
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call(matchers::ANY).and_return_clone(1)
+                        .times(..));
+        scenario.checkpoint();
+        scenario.expect(mock.foo_call(2).and_return(2));
+        assert_eq!(2, mock.foo(2));
+        // Only the cleared expectation would've matched this call
+        let r = panic::catch_unwind(panic::AssertUnwindSafe(|| mock.foo(1)));
+        assert!(r.is_err());
+        // Clearing an unsatisfied expectation fails
+        let r = panic::catch_unwind(|| {
+            let scenario = Scenario::new();
+            let mock = scenario.create_mock_for::<dyn A>();
+            scenario.expect(mock.foo_call(3).and_return(3));
+            scenario.checkpoint();
+        });
+        assert!(r.is_err());
+        print!("verifies ");
+    }
+
+    fn expectation_precedence(){
+        #[mocked]
+        pub trait A {
+            fn foo(&self, x: u32) -> u32;
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call(matchers::ANY).and_return_clone(1)
+                        .times(..));
+        scenario.expect(mock.foo_call(5).and_return_clone(2).times(..));
+        let general_first = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            mock.foo(5)
+        }));
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call(5).and_return_clone(2).times(..));
+        scenario.expect(mock.foo_call(matchers::ANY).and_return_clone(1)
+                        .times(..));
+        let specific_first = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            mock.foo(5)
+        }));
+        print!("{} ", precedence(general_first, specific_first));
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call(matchers::ANY).and_return(1));
+        scenario.expect(mock.foo_call(matchers::ANY).and_return(2));
+        let first = mock.foo(5);
+        let second = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            mock.foo(5)
+        }));
+        let r = saturation(first, second);
+        // Dropping the Scenario would check every expectation, and the second
+        // may never have been called.
+        mem::forget(mock);
+        mem::forget(scenario);
+        print!("{} ", r);
+    }
+
+    fn verification(){
+        // Mockers verifies when the Scenario drops, not the mock
+        #[mocked]
+        pub trait A {
+            fn foo(&self);
+        }
+
+        let r = thread::spawn(|| {
+            let scenario = Scenario::new();
+            let mock = scenario.create_mock_for::<dyn A>();
+            scenario.expect(mock.foo_call().and_return(()));
+        }).join();
+        assert!(r.is_err());
+        print!("drop ");
+
+        print!("{} ", aborts(module_path!(), "verification", || {
+            let scenario = Scenario::new();
+            let mock = scenario.create_mock_for::<dyn A>();
+            scenario.expect(mock.foo_call().and_return(()));
+            panic!("Already panicking");
+        }));
+    }
+
     // Fails to compile with
     // "cannot find type `MockersTypeRegistry` in this scope"
     // and because F doesn't implement Debug.  That's a big problem, because
@@ -104,6 +246,10 @@ impl TestSuite for Mockers {
         //assert_eq!(3, mock.foo(|x| x % 5));
     }
 
+    // Mockers's mocks aren't even Send
+    // https://github.com/kriomant/mockers/issues/22
+    fn concurrency() { unimplemented!() }
+
     fn reference_parameters() {
         #[mocked]
         pub trait A {
@@ -160,7 +306,71 @@ impl TestSuite for Mockers {
 
     fn external_trait(){
         let scenario = Scenario::new();
-        let _mock = scenario.create_mock::<ETMock>();
+        let mock = scenario.create_mock::<DefaultedMock>();
+        scenario.expect(mock.foo_call(4).and_return(5));
+        assert_eq!(5, mock.foo(4));
+        // Default methods needn't be mocked
+        assert_eq!(42, mock.bar());
+
+        // Fails to compile.  Mockers can't mock generic Traits, and its mock!
+        // macro has no syntax for associated types.
+        //mock!{
+            //GenericMock,
+            //external_traits,
+            //trait Generic<T> {
+                //fn foo(&self, t: T) -> u32;
+            //}
+        //}
+        //mock!{
+            //AssociatedMock,
+            //external_traits,
+            //trait Associated {
+                //type Key = u16;
+                //fn foo(&self, k: u16) -> bool;
+            //}
+        //}
+
+        //let g = scenario.create_mock::<GenericMock<i16>>();
+        //scenario.expect(g.foo_call(-1).and_return(42));
+        //assert_eq!(42, g.foo(-1));
+
+        //let a = scenario.create_mock::<AssociatedMock>();
+        //scenario.expect(a.foo_call(1).and_return(true));
+        //assert!(a.foo(1));
+    }
+
+    fn std_traits() {
+        // Read and Write are mocked by the module-level StdMock.  Display's
+        // Formatter argument has a lifetime parameter, which mockers can't
+        // handle (see generic_method_with_lifetime).  Index returns a
+        // reference (see return_reference), and Clone returns Self, so
+        // neither can be mocked.  And mock! has nowhere to set Iterator's
+        // Item type, so this fails to compile:
+        //mock!{
+            //IterMock,
+            //self,
+            //trait Iterator {
+                //type Item;
+                //fn next(&mut self) -> Option<Self::Item>;
+            //}
+        //}
+        let scenario = Scenario::new();
+        let mut mock = scenario.create_mock::<StdMock>();
+        scenario.expect(mock.read_call(matchers::ANY).and_call(|buf| {
+            buf[..3].copy_from_slice(&[1, 2, 3]);
+            Ok(3)
+        }));
+        scenario.expect(mock.write_call(matchers::ANY)
+                        .and_call(|buf: &[u8]| Ok(buf.len())));
+        scenario.expect(mock.flush_call().and_call(|| Ok(())));
+
+        let mut buf = [0u8; 8];
+        assert_eq!(3, mock.read(&mut buf).unwrap());
+        assert_eq!([1, 2, 3], buf[..3]);
+        // write_all is a default method
+        mock.write_all(b"hello").unwrap();
+        mock.flush().unwrap();
+        print!("Read Write ");
     }
 
     fn fallback() {
@@ -190,6 +400,42 @@ impl TestSuite for Mockers {
         unsafe { bar(); }
     }
 
+    fn foreign_parameters() {
+        use std::{ffi::{CStr, CString}, os::raw::c_char};
+
+        #[mocked(LibFfi)]
+        extern "C" {
+            fn get_value(out: *mut i32) -> i32;
+            fn c_strlen(s: *const c_char) -> usize;
+            fn invoke(cb: extern "C" fn(i32) -> i32, x: i32) -> i32;
+        }
+        extern "C" fn double_it(x: i32) -> i32 { 2 * x }
+
+        let scenario = Scenario::new();
+        let ffi = scenario.create_mock::<LibFfi>();
+        scenario.expect(ffi.get_value_call(
+            matchers::check(|out: &*mut i32| !out.is_null()))
+            .and_call(|out: *mut i32| { unsafe { *out = 42; } 0 }));
+        scenario.expect(ffi.c_strlen_call(
+            matchers::check(|s: &*const c_char|
+                unsafe { CStr::from_ptr(*s) }.to_bytes() == b"hello"))
+            .and_call(|s: *const c_char|
+                unsafe { CStr::from_ptr(s) }.to_bytes().len()));
+        scenario.expect(ffi.invoke_call(matchers::ANY, 21)
+            .and_call(|cb: extern "C" fn(i32) -> i32, x: i32| cb(x)));
+
+        let mut out = 0;
+        assert_eq!(0, unsafe { get_value(&mut out) });
+        assert_eq!(42, out);
+        let s = CString::new("hello").unwrap();
+        assert_eq!(5, unsafe { c_strlen(s.as_ptr()) });
+        assert_eq!(42, unsafe { invoke(double_it, 21) });
+    }
+
+    // Mockers mocks traits as trait objects, and traits with generic
+    // associated types aren't object-safe.
+    fn generic_associated_types() { unimplemented!() }
+
     // https://github.com/kriomant/mockers/issues/39
     fn generic_method() {
         register_types!(u32, i16);
@@ -224,6 +470,38 @@ impl TestSuite for Mockers {
         unimplemented!()
     }
 
+    fn generic_non_static() {
+        // Fails to compile.  Mockers identifies a generic method's
+        // instantiations by TypeId, so foo's type parameter must be 'static.
+        // And like generic_method_with_lifetime, bar's borrowed argument
+        // can't be mixed with a generic method.
+        //register_types!(&str, u32);
+        //#[mocked]
+        //pub trait A {
+            //fn foo<T: Debug>(&self, t: T);
+            //fn bar<T: Debug>(&self, t: &T);
+        //}
+
+        //let scenario = Scenario::new();
+        //let mock = scenario.create_mock::<AMock>();
+        //let s = String::from("hello");
+        //scenario.expect(mock.foo_call(&s[..]).and_return(()));
+        //mock.foo(&s[..]);
+        //scenario.expect(mock.bar_call(matchers::by_ref(42u32))
+                        //.and_return(()));
+        //mock.bar(&42u32);
+        unimplemented!()
+    }
+
+    fn lifetime_trait() {
+        // Like generic_method_with_lifetime, fails to compile
+        unimplemented!()
+    }
+    fn lifetime_struct() {
+        // Mockers can't mock structs
+        unimplemented!()
+    }
+
     fn generic_return() {
         register_types!(u32);
 
@@ -245,9 +523,45 @@ impl TestSuite for Mockers {
         unimplemented!();
     }
 
+    // Mockers can't mock generic traits at all
+    fn generic_trait_multi_impl(){ unimplemented!() }
+
+    fn const_generics(){
+        // Fails to compile
+        //#[mocked]
+        //pub trait Buf<const N: usize> {
+            //fn fill(&self) -> [u8; N];
+        //}
+
+        //let scenario = Scenario::new();
+        //let buf2 = scenario.create_mock_for::<dyn Buf<2>>();
+        //scenario.expect(buf2.fill_call().and_return([1, 2]));
+        //assert_eq!([1, 2], buf2.fill());
+        unimplemented!()
+    }
+
     // Can't mock structs
     fn impl_trait() {unimplemented!() }
 
+    // Like closures, the generic parameter can't be named, and needn't be Debug
+    fn impl_trait_argument() { unimplemented!() }
+
+    fn impl_trait_in_trait(){
+        // Fails to compile.  A trait whose method returns "impl Trait" isn't
+        // object-safe, and Mockers mocks traits as trait objects.
+        //#[mocked]
+        //pub trait A {
+            //fn foo(&self) -> impl Iterator<Item = u32>;
+        //}
+
+        //let scenario = Scenario::new();
+        //let mock = scenario.create_mock::<AMock>();
+        //scenario.expect(mock.foo_call()
+                        //.and_return(vec![1, 2, 3].into_iter()));
+        //assert_eq!(vec![1, 2, 3], mock.foo().collect::<Vec<_>>());
+        unimplemented!()
+    }
+
     fn inherited_trait(){
         #[mocked(module="::t_mockers::t")]
         pub trait A {
@@ -373,6 +687,93 @@ impl TestSuite for Mockers {
         mock.foo(5);
     }
 
+    fn match_struct_fields(){
+        #[mocked]
+        pub trait A {
+            fn foo(&self, req: Request) -> u32;
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call(arg!(Request{id: 1, ..}))
+                        .and_return(1));
+        scenario.expect(mock.foo_call(arg!(Request{kind: Kind::Write(_), ..}))
+                        .and_return(2));
+        scenario.expect(mock.foo_call(
+            matchers::check(|r: &Request| r.tags.contains(&42))).and_return(3));
+        let [r1, r2, r3] = Request::samples();
+        assert_eq!(1, mock.foo(r1));
+        assert_eq!(2, mock.foo(r2));
+        assert_eq!(3, mock.foo(r3));
+    }
+
+    // Each of Mockers' matchers sees only one argument
+    fn match_relation() { unimplemented!() }
+
+    fn match_float(){
+        #[mocked]
+        pub trait A {
+            fn foo(&self, x: f64);
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call(
+            matchers::check(|x: &f64| (x - 0.3).abs() < 1e-9)).and_return(()));
+        mock.foo(0.1 + 0.2);
+        let r = panic::catch_unwind(panic::AssertUnwindSafe(|| mock.foo(0.4)));
+        assert!(r.is_err());
+        print!("closure ");
+    }
+
+    fn match_string(){
+        #[mocked]
+        pub trait A {
+            fn foo(&self, s: String);
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call(
+            matchers::check(|s: &String| s.starts_with("hel")))
+            .and_return(()));
+        scenario.expect(mock.foo_call(
+            matchers::check(|s: &String| s.contains("orl")))
+            .and_return(()));
+        mock.foo(String::from("help"));
+        mock.foo(String::from("world"));
+        let r = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            mock.foo(String::from("nope"))
+        }));
+        assert!(r.is_err());
+        print!("closure ");
+    }
+
+    fn match_collection(){
+        #[mocked]
+        pub trait A {
+            fn foo(&self, v: Vec<u32>);
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call(
+            matchers::check(|v: &Vec<u32>| v.contains(&42))).and_return(()));
+        scenario.expect(mock.foo_call(
+            matchers::check(|v: &Vec<u32>| v.len() == 3)).and_return(()));
+        scenario.expect(mock.foo_call(
+            matchers::check(|v: &Vec<u32>| v.iter().all(|&x| x > 0)))
+            .and_return(()));
+        mock.foo(vec![0, 42]);
+        mock.foo(vec![0, 0, 0]);
+        mock.foo(vec![5]);
+        let r = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            mock.foo(vec![0])
+        }));
+        assert!(r.is_err());
+        print!("closure ");
+    }
+
     fn match_wildcard(){
         #[mocked]
         pub trait A {
@@ -407,6 +808,29 @@ impl TestSuite for Mockers {
         foo(mock);
     }
 
+    fn nested_mocks(){
+        #[mocked]
+        pub trait Conn {
+            fn query(&self, q: u32) -> u32;
+        }
+        #[mocked]
+        pub trait Factory {
+            fn conn(&self) -> Box<dyn Conn>;
+        }
+
+        let scenario = Scenario::new();
+        let mut seq = Sequence::new();
+        let factory = scenario.create_mock_for::<dyn Factory>();
+        let conn = scenario.create_mock_for::<dyn Conn>();
+        let query = conn.query_call(1).and_return(42);
+        seq.expect(factory.conn_call()
+                   .and_return(Box::new(conn) as Box<dyn Conn>));
+        seq.expect(query);
+        scenario.expect(seq);
+        assert_eq!(42, factory.conn().query(1));
+        print!("nested sequence ");
+    }
+
     fn return_call_with_args(){
         #[mocked]
         pub trait A {
@@ -443,6 +867,23 @@ impl TestSuite for Mockers {
         assert_eq!(mock.foo(), 0);
     }
 
+    fn return_dyn_iterator() {
+        // Mockers can only return 'static values, so the iterator can't borrow
+        // from the mock.
+        #[mocked]
+        pub trait A {
+            fn foo(&self) -> Box<dyn Iterator<Item = u32>>;
+            //fn bar(&self) -> Box<dyn Iterator<Item = u32> + '_>;
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call()
+                        .and_call(|| Box::new(vec![1, 2, 3].into_iter())));
+        assert_eq!(vec![1, 2, 3], mock.foo().collect::<Vec<_>>());
+        print!("static ");
+    }
+
     fn return_reference(){
         //#[mocked]
         //pub trait A {
@@ -455,6 +896,38 @@ impl TestSuite for Mockers {
     }
 
     fn return_mutable_reference() { unimplemented!() }
+    fn deref(){
+        #[mocked]
+        pub trait A {
+            fn foo(&self, x: u32) -> u32;
+        }
+
+        let scenario = Scenario::new();
+        let handle = Handle(scenario.create_mock_for::<dyn A>());
+        scenario.expect(handle.foo_call(1).and_return_clone(42).times(3));
+        for m in handle.views().iter() {
+            let a: &dyn A = *m;
+            assert_eq!(42, a.foo(1));
+        }
+        print!("deref ");
+
+        // Fails to compile.  Like return_reference, mockers can't return
+        // references at all, so it can't return &dyn OtherTrait.
+        //#[mocked]
+        //pub trait C {
+            //fn bar(&self) -> u32;
+        //}
+        //#[mocked]
+        //pub trait B {
+            //fn other(&self) -> &dyn C;
+        //}
+        //let c = scenario.create_mock_for::<dyn C>();
+        //scenario.expect(c.bar_call().and_return(7));
+        //let b = scenario.create_mock_for::<dyn B>();
+        //scenario.expect(b.other_call().and_return(&c as &dyn C));
+        //assert_eq!(7, b.other().bar());
+    }
+
     fn return_owned(){
         #[mocked]
         pub trait A {
@@ -468,6 +941,25 @@ impl TestSuite for Mockers {
         assert_eq!(mock.foo(), UniquelyOwned(42));
     }
 
+    fn return_result(){
+        #[mocked]
+        pub trait A {
+            fn foo(&self, x: u32) -> io::Result<u32>;
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call(0)
+            .and_call_clone(|_| {
+                Err(io::Error::new(io::ErrorKind::Other, "Oops"))
+            }).times(..));
+        scenario.expect(mock.foo_call(1).and_call(|x| Ok(x)));
+        assert!(mock.foo(0).is_err());
+        assert!(mock.foo(0).is_err());
+        assert_eq!(1, mock.foo(1).unwrap());
+        print!("times_any ");
+    }
+
     fn return_panic(){
         #[mocked]
         pub trait A {
@@ -496,6 +988,24 @@ impl TestSuite for Mockers {
         assert_eq!(value, 2);
     }
 
+    fn return_sequence() {
+        // By default, Mockers expects each expectation to be called once
+        #[mocked]
+        pub trait A {
+            fn foo(&self) -> Result<u32, String>;
+        }
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call().and_return(Ok(1)));
+        scenario.expect(mock.foo_call().and_return(Ok(2)));
+        scenario.expect(mock.foo_call().and_return(Err(String::from("Oops"))));
+        assert_eq!(Ok(1), mock.foo());
+        assert_eq!(Ok(2), mock.foo());
+        assert!(mock.foo().is_err());
+        print!("times(1) ");
+    }
+
     // https://github.com/kriomant/mockers/issues/22
     fn send() {
         //#[mocked]
@@ -525,6 +1035,56 @@ impl TestSuite for Mockers {
         assert_eq!(99, AMock::bar());
     }
 
+    fn static_isolation() {
+        #[mocked]
+        pub trait A {
+            fn foo(&self) -> u32;
+            fn bar() -> u32;
+        }
+
+        let barrier = Arc::new(Barrier::new(2));
+        let handles = (0..2).map(|i| {
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                let scenario = Scenario::new();
+                let mock_class = scenario.create_mock::<AMockStatic>();
+                scenario.expect(mock_class.bar_call()
+                                .and_return_clone(i).times(..));
+                barrier.wait();
+                let r = AMock::bar();
+                barrier.wait();
+                assert_eq!(i, r);
+            })
+        }).collect::<Vec<_>>();
+        for h in handles {
+            h.join().unwrap();
+        }
+        print!("per-thread ");
+    }
+
+    fn constructor() {
+        #[mocked]
+        pub trait A {
+            fn new(cfg: u32) -> Self;
+            fn try_new(cfg: u32) -> Result<Self, String> where Self: Sized;
+            fn foo(&self) -> u32;
+        }
+
+        fn build<T: A>(cfg: u32) -> u32 {
+            T::new(cfg).foo()
+        }
+
+        let scenario = Scenario::new();
+        let mock_class = scenario.create_mock::<AMockStatic>();
+        let instance = scenario.create_mock::<AMock>();
+        scenario.expect(instance.foo_call().and_return(5));
+        scenario.expect(mock_class.new_call(5).and_return(instance));
+        scenario.expect(mock_class.try_new_call(0)
+                        .and_return(Err(String::from("invalid"))));
+        assert_eq!(5, build::<AMock>(5));
+        assert!(AMock::try_new(0).is_err());
+    }
+
     fn sequence(){
         #[mocked]
         pub trait A {
@@ -581,6 +1141,13 @@ impl TestSuite for Mockers {
         scenario.expect(mock.foo_call().never());
     }
 
+    // The Scenario matches each call against its expectations and then
+    // discards it, so there's nothing to inspect afterwards.
+    fn call_history() { unimplemented!() }
+
+    // Mockers can only verify calls against expectations set beforehand
+    fn spy() { unimplemented!() }
+
     fn times_once(){
         // By default, Mockers expects a mock to be called once
         #[mocked]
@@ -606,6 +1173,42 @@ impl TestSuite for Mockers {
         mock.foo();
     }
 
+    fn unsafe_methods(){
+        #[mocked]
+        pub trait A {
+            unsafe fn foo(&self, x: i16) -> i32;
+        }
+        #[mocked]
+        pub unsafe trait B {
+            fn foo(&self, x: i16) -> i32;
+        }
+
+        let scenario = Scenario::new();
+        let a = scenario.create_mock_for::<dyn A>();
+        let b = scenario.create_mock_for::<dyn B>();
+        scenario.expect(a.foo_call(4).and_return(5));
+        scenario.expect(b.foo_call(4).and_return(5));
+        assert_eq!(5, unsafe { a.foo(4) });
+        assert_eq!(5, b.foo(4));
+    }
+
+    fn attributes() {
+        #[deny(warnings)]
+        mod m {
+            use mockers_derive::mocked;
+
+            attributes_trait!(#[mocked] A);
+        }
+        use m::*;
+
+        let scenario = Scenario::new();
+        let mock = scenario.create_mock_for::<dyn A>();
+        scenario.expect(mock.foo_call().and_return(1));
+        scenario.expect(mock.old_call().and_return(2));
+        scenario.expect(mock.checked_call().and_return(3));
+        check_attributes(&mock);
+    }
+
     fn version() {
         let ver = crate::built_info::DEPENDENCIES.iter()
             .find(|(name, _)| *name == "mockers")
@@ -20,10 +20,14 @@ use mocktopus::macros::*;
 use mocktopus::mocking::*;
 use std::{
     cell::RefCell,
-    sync::Arc
+    fmt,
+    io,
+    panic,
+    sync::{Arc, Barrier, Mutex},
+    thread
 };
 
-use crate::{TestSuite, UniquelyOwned};
+use crate::{Kind, Request, TestSuite, UniquelyOwned};
 
 struct Mocktopus {}
 #[allow(unused_parens)]
@@ -34,11 +38,56 @@ impl TestSuite for Mocktopus{
         unimplemented!()
     }
 
+    fn associated_consts() {
+        // mocktopus can't mock traits
+        unimplemented!()
+    }
+
+    fn associated_type_bounds() {
+        // mocktopus can't mock traits
+        unimplemented!()
+    }
+
     fn checkpoint() {
         // mocktopus does not have expectations
         unimplemented!()
     }
 
+    fn reset() {
+        // Mocktopus has no expectations to verify, but clear_mocks restores
+        // every function's original behavior.
+        #[mockable]
+        fn foo(x: u32) -> u32 { x }
+
+        foo.mock_safe(|_| MockResult::Return(42));
+        assert_eq!(42, foo(1));
+        clear_mocks();
+        assert_eq!(1, foo(1));
+        foo.mock_safe(|_| MockResult::Return(43));
+        assert_eq!(43, foo(1));
+        print!("discards ");
+    }
+
+    // mocktopus has no expectations, only a single mock closure per function
+    fn expectation_precedence() { unimplemented!() }
+
+    fn verification() {
+        // Mocktopus has no expectations, so an unused mock passes quietly.  It
+        // doesn't record calls either, so the test must count them inside the
+        // closure.
+        #[mockable]
+        fn foo() {}
+
+        let calls = Arc::new(Mutex::new(0));
+        let calls2 = calls.clone();
+        foo.mock_safe(move || {
+            *calls2.lock().unwrap() += 1;
+            MockResult::Return(())
+        });
+        assert_eq!(0, *calls.lock().unwrap());
+        print!("explicit ");
+    }
+
     // Mocktopus can't do this because mock_safe requires naming the argument.
     // Closures usually can't be named.
     fn closures() {
@@ -52,6 +101,12 @@ impl TestSuite for Mocktopus{
         //assert_eq!(3, foo(|x| x % 5));
     }
 
+    fn concurrency() {
+        // Mocktopus's mocks are thread-local, so a function mocked in one
+        // thread retains its original behavior in every other thread.
+        unimplemented!()
+    }
+
     fn reference_parameters() {
         #[mockable]
         fn foo(x: &u32) -> u32 {*x}
@@ -105,6 +160,11 @@ impl TestSuite for Mocktopus{
         unimplemented!();
     }
 
+    fn std_traits() {
+        // mocktopus can't mock traits
+        unimplemented!();
+    }
+
     // Mocktopus's clear, clear_mock, and MockContext functions are related to
     // fallback.
     fn fallback() {
@@ -139,6 +199,14 @@ impl TestSuite for Mocktopus{
         unimplemented!();
     }
 
+    // mock_safe is not implemented for extern functions
+    fn foreign_parameters() { unimplemented!() }
+
+    fn generic_associated_types() {
+        // mocktopus can't mock traits
+        unimplemented!()
+    }
+
     fn generic_method() {
         #[mockable]
         fn foo<T: Clone + Default>(_t:T) -> T {T::default()}
@@ -162,6 +230,45 @@ impl TestSuite for Mocktopus{
         foo(x);
     }
 
+    fn generic_non_static() {
+        // Mocktopus identifies mocked functions by TypeId.  A function's
+        // lifetime parameters aren't part of its type, which is why
+        // generic_method_with_lifetime works, but its type parameters are, so
+        // they must be 'static.  That rules out foo::<&str>:
+        //#[mockable]
+        //fn foo<T: Debug>(_t: T) {}
+        //foo.mock_safe(|_t: &str| MockResult::Return(()));
+        //let s = String::from("hello");
+        //foo(&s[..]);
+        #[mockable]
+        fn bar<T: fmt::Debug>(_t: &T) -> bool { false }
+
+        bar.mock_safe(|t: &u32| MockResult::Return(*t == 42));
+        let x = 42u32;
+        assert!(bar(&x));
+        print!("reference_only ");
+    }
+
+    fn lifetime_trait() {
+        // mocktopus can't mock traits
+        unimplemented!()
+    }
+    fn lifetime_struct() {
+        struct Reader<'a>(&'a str);
+        #[mockable]
+        impl<'a> Reader<'a> {
+            fn split<'b>(&self, _s: &'b str) -> &'b str {
+                unimplemented!()
+            }
+        }
+
+        Reader::split.mock_safe(|_self, s| MockResult::Return(&s[..5]));
+        let buf = String::from("buffer");
+        let reader = Reader(&buf);
+        let s = String::from("hello world");
+        assert_eq!("hello", reader.split(&s));
+    }
+
     fn generic_return() {
         #[mockable]
         fn foo<T: Default>() -> T {T::default()}
@@ -188,6 +295,20 @@ impl TestSuite for Mocktopus{
         // Mocktopus can't mock traits
     }
 
+    // Mocktopus can't mock traits
+    fn generic_trait_multi_impl() { unimplemented!() }
+
+    fn const_generics() {
+        // Fails to compile.  mocktopus can't mock traits, so this only tries a
+        // function with a const generic parameter.
+        //#[mockable]
+        //fn read<const N: usize>() -> [u8; N] { [0; N] }
+
+        //read::<2>.mock_safe(|| MockResult::Return([5, 6]));
+        //assert_eq!([5, 6], read::<2>());
+        unimplemented!()
+    }
+
     fn impl_trait() {
         unimplemented!()
         // Build fails: "expected opaque type, found struct `std::boxed::Box`"
@@ -205,6 +326,16 @@ impl TestSuite for Mocktopus{
         //assert_eq!("\"Hello, World!\"", r);
     }
 
+    fn impl_trait_argument() {
+        // Like closures, mock_safe requires naming the argument's type
+        unimplemented!()
+    }
+
+    fn impl_trait_in_trait() {
+        // mocktopus can't mock traits
+        unimplemented!()
+    }
+
     fn inherited_trait() {
         // mocktopus can't mock traits
         unimplemented!()
@@ -260,6 +391,107 @@ impl TestSuite for Mocktopus{
         unimplemented!()
     }
 
+    fn match_struct_fields() {
+        // Like match_method, mocktopus can only match arguments inside of its
+        // closure.
+        #[mockable]
+        fn foo(_req: Request) -> u32 { unimplemented!() }
+
+        foo.mock_safe(|r| {
+            if r.id == 1 {
+                MockResult::Return(1)
+            } else if matches!(r.kind, Kind::Write(_)) {
+                MockResult::Return(2)
+            } else if r.tags.contains(&42) {
+                MockResult::Return(3)
+            } else {
+                panic!("Unexpected argument {:?}", r)
+            }
+        });
+        let [r1, r2, r3] = Request::samples();
+        assert_eq!(1, foo(r1));
+        assert_eq!(2, foo(r2));
+        assert_eq!(3, foo(r3));
+    }
+
+    fn match_relation() {
+        // Like match_method, mocktopus can only match arguments inside of its
+        // closure.
+        #[mockable]
+        fn foo(_a: u32, _b: u32) {}
+        #[mockable]
+        fn bar(_buf: Vec<u8>, _n: usize) {}
+
+        foo.mock_safe(|a, b| {
+            assert!(a < b);
+            MockResult::Return(())
+        });
+        bar.mock_safe(|buf, n| {
+            assert_eq!(buf.len(), n);
+            MockResult::Return(())
+        });
+        foo(1, 2);
+        bar(vec![0; 3], 3);
+        assert!(panic::catch_unwind(|| foo(2, 1)).is_err());
+        print!("closure ");
+    }
+
+    fn match_float() {
+        // Like match_method, mocktopus can only match arguments inside of its
+        // closure.
+        #[mockable]
+        fn foo(_x: f64) {}
+
+        foo.mock_safe(|x| {
+            assert!((x - 0.3).abs() < 1e-9);
+            MockResult::Return(())
+        });
+        foo(0.1 + 0.2);
+        assert!(panic::catch_unwind(|| foo(0.4)).is_err());
+        print!("closure ");
+    }
+
+    fn match_string() {
+        #[mockable]
+        fn foo(_s: &str) -> u32 { unimplemented!() }
+
+        foo.mock_safe(|s| {
+            if s.starts_with("hel") {
+                MockResult::Return(1)
+            } else if s.contains("orl") {
+                MockResult::Return(2)
+            } else {
+                panic!("Unexpected argument {:?}", s)
+            }
+        });
+        assert_eq!(1, foo("help"));
+        assert_eq!(2, foo("world"));
+        assert!(panic::catch_unwind(|| foo("nope")).is_err());
+        print!("closure ");
+    }
+
+    fn match_collection() {
+        #[mockable]
+        fn foo(_v: Vec<u32>) -> u32 { unimplemented!() }
+
+        foo.mock_safe(|v| {
+            if v.contains(&42) {
+                MockResult::Return(1)
+            } else if v.len() == 3 {
+                MockResult::Return(2)
+            } else if v.iter().all(|&x| x > 0) {
+                MockResult::Return(3)
+            } else {
+                panic!("Unexpected argument {:?}", v)
+            }
+        });
+        assert_eq!(1, foo(vec![0, 42]));
+        assert_eq!(2, foo(vec![0, 0, 0]));
+        assert_eq!(3, foo(vec![5]));
+        assert!(panic::catch_unwind(|| foo(vec![0])).is_err());
+        print!("closure ");
+    }
+
     fn match_wildcard() {
         // mocktopus has no expectations
         unimplemented!()
@@ -299,6 +531,9 @@ impl TestSuite for Mocktopus{
         unimplemented!()
     }
 
+    // mocktopus can't mock traits
+    fn nested_mocks() { unimplemented!() }
+
     fn return_call_with_args() { 
         #[mockable]
         fn foo(x: i16) -> i16 {0}
@@ -315,6 +550,24 @@ impl TestSuite for Mocktopus{
         unimplemented!()
     }
 
+    fn return_dyn_iterator() {
+        #[derive(Default)]
+        struct A {}
+        #[mockable]
+        impl A {
+            pub fn foo(&self) -> Box<dyn Iterator<Item = u32> + '_> {
+                unimplemented!()
+            }
+        }
+
+        A::foo.mock_safe(|_s|
+            MockResult::Return(Box::new(vec![1, 2, 3].into_iter()))
+        );
+        let a = A::default();
+        assert_eq!(vec![1, 2, 3], a.foo().collect::<Vec<_>>());
+        print!("static ");
+    }
+
     fn return_reference() {
         // Mocktopus mocks can only return references to 'static variables
         //let x = 5u32;
@@ -333,6 +586,10 @@ impl TestSuite for Mocktopus{
     }
 
     fn return_mutable_reference() { unimplemented!() }
+
+    // mocktopus can't mock traits
+    fn deref() { unimplemented!() }
+
     // https://github.com/CodeSandwich/Mocktopus/issues/34
     fn return_owned() { 
         // mock_safe and mock_raw both take FnMut arguments
@@ -347,6 +604,24 @@ impl TestSuite for Mocktopus{
         //assert_eq!(UniquelyOwned(42), foo());
     }
 
+    fn return_result() {
+        #[mockable]
+        fn foo(_x: u32) -> io::Result<u32> { unimplemented!() }
+
+        foo.mock_safe(|x| {
+            if x == 0 {
+                MockResult::Return(Err(io::Error::new(io::ErrorKind::Other,
+                                                      "Oops")))
+            } else {
+                MockResult::Return(Ok(x))
+            }
+        });
+        assert!(foo(0).is_err());
+        assert!(foo(0).is_err());
+        assert_eq!(1, foo(1).unwrap());
+        print!("times_any ");
+    }
+
     fn return_panic() {
         unimplemented!()
     }
@@ -365,6 +640,25 @@ impl TestSuite for Mocktopus{
         assert_eq!(value, 42);
     }
 
+    fn return_sequence() {
+        #[mockable]
+        fn foo() -> Result<u32, String> { unimplemented!() }
+
+        let mut count = 0;
+        foo.mock_safe(move || {
+            count += 1;
+            match count {
+                1 => MockResult::Return(Ok(1)),
+                2 => MockResult::Return(Ok(2)),
+                _ => MockResult::Return(Err(String::from("Oops")))
+            }
+        });
+        assert_eq!(Ok(1), foo());
+        assert_eq!(Ok(2), foo());
+        assert!(foo().is_err());
+        print!("closure ");
+    }
+
     fn send() {
         // Mocktopus can't create mock objects at all
         unimplemented!()
@@ -392,6 +686,51 @@ impl TestSuite for Mocktopus{
         assert_eq!(42u32, AS::bar());
     }
 
+    fn static_isolation() {
+        // Mocktopus's mocks are thread-local, so concurrent tests can't see
+        // each other's mocks.
+        #[mockable]
+        fn bar() -> u32 {0}
+
+        let barrier = Arc::new(Barrier::new(2));
+        let handles = (0..2).map(|i| {
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                bar.mock_safe(move || MockResult::Return(i));
+                barrier.wait();
+                let r = bar();
+                barrier.wait();
+                assert_eq!(i, r);
+            })
+        }).collect::<Vec<_>>();
+        for h in handles {
+            h.join().unwrap();
+        }
+        print!("per-thread ");
+    }
+
+    fn constructor() {
+        // Mocktopus mocks methods, not instances, so every object that the
+        // constructor returns shares the same mocked methods.
+        struct Conn {
+            cfg: u32
+        }
+        #[mockable]
+        impl Conn {
+            fn new(_cfg: u32) -> Self { unimplemented!() }
+            fn try_new(_cfg: u32) -> Result<Self, String> { unimplemented!() }
+            fn foo(&self) -> u32 { unimplemented!() }
+        }
+
+        Conn::new.mock_safe(|cfg| MockResult::Return(Conn{cfg}));
+        Conn::try_new.mock_safe(|_| {
+            MockResult::Return(Err(String::from("invalid")))
+        });
+        Conn::foo.mock_safe(|conn| MockResult::Return(conn.cfg));
+        assert_eq!(5, Conn::new(5).foo());
+        assert!(Conn::try_new(0).is_err());
+    }
+
     fn sequence() {
         // mocktopus does not have any sequence support
         unimplemented!()
@@ -417,11 +756,57 @@ impl TestSuite for Mocktopus{
         unimplemented!()
     }
 
+    // Mocktopus only swaps a closure in for the real function.  There's no
+    // mock object to keep the calls in.
+    fn call_history() { unimplemented!() }
+
+    // mocktopus does not have expectations, nor does it record calls
+    fn spy() { unimplemented!() }
+
     fn times_range() { 
         // mocktopus does not verify call counts
         unimplemented!()
     }
 
+    fn unsafe_methods() {
+        // Mocktopus can't mock traits.  And it can't mock unsafe struct methods
+        // either, because mock_safe requires the function to implement FnOnce,
+        // which unsafe functions don't.
+        unimplemented!()
+    }
+
+    fn attributes() {
+        // Mocktopus can't mock traits, so this tests free functions instead
+        #[deny(warnings)]
+        mod m {
+            use mocktopus::macros::*;
+
+            /// An ordinary, documented function
+            #[mockable]
+            pub fn foo() -> u32 { 0 }
+            #[cfg(any())]
+            #[mockable]
+            pub fn gated() -> u32 { 0 }
+            #[deprecated(note = "Use foo instead")]
+            #[mockable]
+            pub fn old() -> u32 { 0 }
+            #[must_use]
+            #[mockable]
+            pub fn checked() -> u32 { 0 }
+        }
+        use m::*;
+
+        foo.mock_safe(|| MockResult::Return(1));
+        #[allow(deprecated)]
+        old.mock_safe(|| MockResult::Return(2));
+        checked.mock_safe(|| MockResult::Return(3));
+        assert_eq!(1, foo());
+        #[allow(deprecated)]
+        let r = old();
+        assert_eq!(2, r);
+        assert_eq!(3, checked());
+    }
+
     fn version() {
         let ver = crate::built_info::DEPENDENCIES.iter()
             .find(|(name, _)| *name == "mocktopus")